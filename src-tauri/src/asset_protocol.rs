@@ -0,0 +1,203 @@
+// Custom `lost-asset://` protocol for streaming binary project assets (tilesets, entity
+// images) directly to the frontend without a base64/IPC round-trip.
+
+use std::fs;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use tauri::http::{status::StatusCode, Request, Response};
+
+/// Guess a MIME type from a file extension. Falls back to `application/octet-stream`
+/// for anything we don't recognize, which is safe for `<img>`/`<video>` consumers.
+fn mime_type_for(path: &Path) -> &'static str {
+    match path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("bmp") => "image/bmp",
+        Some("json") => "application/json",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Resolve the URI path portion of a `lost-asset://` request into an absolute path
+/// under `project_root`. A path that escapes the project directory (e.g. via `..`
+/// segments) is rejected with `FORBIDDEN`; a path that's in scope but doesn't exist
+/// is rejected with `NOT_FOUND`, so the two stay distinguishable to the caller. A
+/// path whose parent directory doesn't exist is `NOT_FOUND` wherever it points.
+fn resolve_asset_path(project_root: &Path, uri_path: &str) -> Result<PathBuf, StatusCode> {
+    let relative = uri_path.trim_start_matches('/');
+    let decoded = percent_decode(relative);
+    let candidate = project_root.join(decoded);
+
+    // The file itself may not exist (that's a 404, not a scope violation), so
+    // canonicalize its parent and rejoin the file name.
+    let canonical = match candidate.canonicalize() {
+        Ok(canonical) => canonical,
+        Err(_) => {
+            let parent = candidate.parent().ok_or(StatusCode::NOT_FOUND)?;
+            let file_name = candidate.file_name().ok_or(StatusCode::NOT_FOUND)?;
+            parent
+                .canonicalize()
+                .map_err(|_| StatusCode::NOT_FOUND)?
+                .join(file_name)
+        }
+    };
+
+    if canonical.starts_with(project_root) {
+        Ok(canonical)
+    } else {
+        Err(StatusCode::FORBIDDEN)
+    }
+}
+
+/// Minimal percent-decoder for the path segments `lost-asset://` URIs carry
+/// (spaces and the handful of reserved characters tileset paths tend to contain).
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let Ok(byte) = u8::from_str_radix(&input[i + 1..i + 3], 16) {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn empty_response(status: StatusCode) -> Response<Vec<u8>> {
+    Response::builder()
+        .status(status)
+        .body(Vec::new())
+        .unwrap()
+}
+
+/// Handle a `lost-asset://` request: map it to a file under the currently open
+/// project, honor `Range` requests for partial loads of large atlases, and return
+/// 403/404 instead of panicking on missing or out-of-scope paths.
+pub fn handle_asset_request(
+    app: &tauri::AppHandle,
+    request: &Request<Vec<u8>>,
+) -> Response<Vec<u8>> {
+    let project_root = match crate::project_scope::current_root(app) {
+        Some(root) => root,
+        None => return empty_response(StatusCode::FORBIDDEN),
+    };
+
+    let path = match resolve_asset_path(&project_root, request.uri().path()) {
+        Ok(path) => path,
+        Err(status) => return empty_response(status),
+    };
+
+    let mut file = match fs::File::open(&path) {
+        Ok(file) => file,
+        Err(_) => return empty_response(StatusCode::NOT_FOUND),
+    };
+
+    let file_len = match file.metadata() {
+        Ok(metadata) => metadata.len(),
+        Err(_) => return empty_response(StatusCode::NOT_FOUND),
+    };
+
+    let mime = mime_type_for(&path);
+
+    if let Some(range_header) = request.headers().get("range").and_then(|v| v.to_str().ok()) {
+        match parse_range(range_header, file_len) {
+            Some(ParsedRange::Satisfiable(start, end)) => {
+                let len = (end - start + 1) as usize;
+                let mut buf = vec![0u8; len];
+                if file.seek(SeekFrom::Start(start)).is_err() || file.read_exact(&mut buf).is_err()
+                {
+                    return empty_response(StatusCode::NOT_FOUND);
+                }
+
+                return Response::builder()
+                    .status(StatusCode::PARTIAL_CONTENT)
+                    .header("Content-Type", mime)
+                    .header("Accept-Ranges", "bytes")
+                    .header(
+                        "Content-Range",
+                        format!("bytes {}-{}/{}", start, end, file_len),
+                    )
+                    .header("Content-Length", buf.len().to_string())
+                    .body(buf)
+                    .unwrap();
+            }
+            Some(ParsedRange::Unsatisfiable) => {
+                return Response::builder()
+                    .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                    .header("Content-Range", format!("bytes */{}", file_len))
+                    .body(Vec::new())
+                    .unwrap();
+            }
+            // A header that doesn't parse as a `bytes=` range is ignored, per the
+            // HTTP spec, and the request is served as a normal full-file response.
+            None => {}
+        }
+    }
+
+    let mut buf = Vec::with_capacity(file_len as usize);
+    if file.read_to_end(&mut buf).is_err() {
+        return empty_response(StatusCode::NOT_FOUND);
+    }
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header("Content-Type", mime)
+        .header("Accept-Ranges", "bytes")
+        .header("Content-Length", buf.len().to_string())
+        .body(buf)
+        .unwrap()
+}
+
+enum ParsedRange {
+    /// An inclusive `(start, end)` byte range that fits within the file.
+    Satisfiable(u64, u64),
+    /// A syntactically valid range that doesn't fit the file (e.g. `start` past EOF).
+    Unsatisfiable,
+}
+
+/// Parse a single-range `Range: bytes=start-end` header, including the suffix form
+/// `bytes=-N` (the last `N` bytes). Multi-range requests are not supported. Returns
+/// `None` for a header that isn't a `bytes=` range at all, so the caller can ignore
+/// it rather than reject the request outright.
+fn parse_range(header: &str, file_len: u64) -> Option<ParsedRange> {
+    let spec = header.strip_prefix("bytes=")?;
+    let (start_str, end_str) = spec.split_once('-')?;
+
+    if start_str.is_empty() {
+        let suffix_len: u64 = end_str.parse().ok()?;
+        return Some(if suffix_len == 0 || file_len == 0 {
+            ParsedRange::Unsatisfiable
+        } else {
+            ParsedRange::Satisfiable(file_len.saturating_sub(suffix_len), file_len - 1)
+        });
+    }
+
+    let start: u64 = start_str.parse().ok()?;
+    let end: u64 = if end_str.is_empty() {
+        file_len.saturating_sub(1)
+    } else {
+        end_str.parse().ok()?
+    };
+
+    if start > end || start >= file_len {
+        return Some(ParsedRange::Unsatisfiable);
+    }
+
+    Some(ParsedRange::Satisfiable(start, end.min(file_len.saturating_sub(1))))
+}
@@ -0,0 +1,201 @@
+// Crash-safe writes: every save goes through a temp file + fsync + rename so a
+// power loss or panic mid-write can never leave a truncated project or settings
+// file, and the previous contents are kept as a timestamped `.bak` snapshot.
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::project_scope;
+
+/// Default number of `.bak` snapshots to keep per file before the oldest is
+/// pruned, used when `settings.json` has no `backupRetention` override.
+const DEFAULT_BACKUP_RETENTION: usize = 10;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupSnapshot {
+    id: String,
+    path: String,
+}
+
+/// Write `data` to `path` atomically: the new contents land in a sibling `.tmp`
+/// file that is fsync'd and renamed over the destination, the rename itself is
+/// fsync'd via the parent directory so it can't be lost on power loss, and the
+/// previous contents (if any) are preserved as a new `.bak` snapshot first.
+pub fn atomic_write(app: &tauri::AppHandle, path: &Path, data: &[u8]) -> Result<(), String> {
+    if path.exists() {
+        backup_existing(path)?;
+    }
+
+    let tmp_path = tmp_path_for(path);
+
+    {
+        let mut tmp_file = File::create(&tmp_path).map_err(|e| e.to_string())?;
+        tmp_file.write_all(data).map_err(|e| e.to_string())?;
+        tmp_file.sync_all().map_err(|e| e.to_string())?;
+    }
+
+    fs::rename(&tmp_path, path).map_err(|e| e.to_string())?;
+    sync_parent_dir(path)?;
+    prune_old_backups(path, retention_count(app))?;
+
+    Ok(())
+}
+
+/// Fsync the parent directory so the `rename` that lands the new contents is
+/// itself durable, not just the file data. A no-op on Windows, where directory
+/// handles can't be opened for syncing.
+#[cfg(unix)]
+fn sync_parent_dir(path: &Path) -> Result<(), String> {
+    let parent = path.parent().unwrap_or_else(|| Path::new("."));
+    File::open(parent)
+        .and_then(|dir| dir.sync_all())
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(not(unix))]
+fn sync_parent_dir(_path: &Path) -> Result<(), String> {
+    Ok(())
+}
+
+fn retention_count(app: &tauri::AppHandle) -> usize {
+    fs::read_to_string(crate::get_settings_path(app))
+        .ok()
+        .and_then(|data| serde_json::from_str::<serde_json::Value>(&data).ok())
+        .and_then(|settings| settings.get("backupRetention").and_then(|v| v.as_u64()))
+        .map(|n| n as usize)
+        .unwrap_or(DEFAULT_BACKUP_RETENTION)
+}
+
+fn tmp_path_for(path: &Path) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!("{}.tmp", file_name))
+}
+
+fn backup_dir_for(path: &Path) -> PathBuf {
+    path.with_file_name(format!(
+        "{}.backups",
+        path.file_name().unwrap_or_default().to_string_lossy()
+    ))
+}
+
+fn backup_existing(path: &Path) -> Result<(), String> {
+    let backup_dir = backup_dir_for(path);
+    fs::create_dir_all(&backup_dir).map_err(|e| e.to_string())?;
+
+    let timestamp = fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+
+    let backup_path = unique_backup_path(&backup_dir, timestamp);
+    fs::copy(path, &backup_path).map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Pick a `.bak` path for `timestamp` under `backup_dir`, appending a counter
+/// suffix if that timestamp is already taken (two saves landing in the same
+/// mtime tick on a coarse-granularity filesystem).
+fn unique_backup_path(backup_dir: &Path, timestamp: u128) -> PathBuf {
+    let mut candidate = backup_dir.join(format!("{}.bak", timestamp));
+    let mut suffix = 1u32;
+    while candidate.exists() {
+        candidate = backup_dir.join(format!("{}-{}.bak", timestamp, suffix));
+        suffix += 1;
+    }
+    candidate
+}
+
+fn prune_old_backups(path: &Path, retention: usize) -> Result<(), String> {
+    let mut backups = list_backups_impl(path)?;
+    if backups.len() <= retention {
+        return Ok(());
+    }
+
+    // `list_backups_impl` returns newest first; drop everything past the retention count.
+    for snapshot in backups.split_off(retention) {
+        let _ = fs::remove_file(snapshot.path);
+    }
+
+    Ok(())
+}
+
+/// List available `.bak` snapshots for `path`, newest first.
+fn list_backups_impl(path: &Path) -> Result<Vec<BackupSnapshot>, String> {
+    let backup_dir = backup_dir_for(path);
+    if !backup_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries: Vec<PathBuf> = fs::read_dir(&backup_dir)
+        .map_err(|e| e.to_string())?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("bak"))
+        .collect();
+
+    // Order by (timestamp, collision suffix) so `<ts>-1.bak`, written after
+    // `<ts>.bak`, counts as the newer of the two.
+    entries.sort_by_key(|p| {
+        let stem = p
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let (timestamp, suffix) = stem.split_once('-').unwrap_or((stem.as_str(), "0"));
+        (
+            timestamp.parse::<u128>().unwrap_or(0),
+            suffix.parse::<u32>().unwrap_or(0),
+        )
+    });
+    entries.reverse();
+
+    Ok(entries
+        .into_iter()
+        .map(|p| BackupSnapshot {
+            id: p
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            path: p.to_string_lossy().into_owned(),
+        })
+        .collect())
+}
+
+/// Restore `path` from the `.bak` snapshot identified by `backup_id`, going
+/// through the same atomic write path so the restore itself can't corrupt it.
+fn restore_backup_impl(app: &tauri::AppHandle, path: &Path, backup_id: &str) -> Result<(), String> {
+    let backup_path = backup_dir_for(path).join(format!("{}.bak", backup_id));
+    let data = fs::read(&backup_path).map_err(|e| e.to_string())?;
+    atomic_write(app, path, &data)
+}
+
+/// List the available backup snapshots for `file_path`, newest first. Routed
+/// through the project scope so a caller can't probe backups outside it.
+#[tauri::command]
+pub async fn list_backups(
+    app: tauri::AppHandle,
+    file_path: String,
+) -> Result<Vec<BackupSnapshot>, String> {
+    let scoped_path = project_scope::resolve_scoped_path(&app, &file_path)?;
+    list_backups_impl(&scoped_path)
+}
+
+/// Restore `file_path` from the snapshot identified by `backup_id`. Routed
+/// through the project scope so a caller can't overwrite files outside it.
+#[tauri::command]
+pub async fn restore_backup(
+    app: tauri::AppHandle,
+    file_path: String,
+    backup_id: String,
+) -> Result<(), String> {
+    let scoped_path = project_scope::resolve_scoped_path(&app, &file_path)?;
+    restore_backup_impl(&app, &scoped_path, &backup_id)
+}
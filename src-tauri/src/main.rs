@@ -1,9 +1,16 @@
 // Prevents additional console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod asset_protocol;
+mod backup;
+mod project_scope;
+mod recent_projects;
+mod updater;
+mod watcher;
+
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tauri::{Emitter, Manager};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -23,8 +30,19 @@ struct Settings {
 
 // File operations
 #[tauri::command]
-async fn read_file(file_path: String) -> FileResult {
-    match fs::read_to_string(&file_path) {
+async fn read_file(app: tauri::AppHandle, file_path: String) -> FileResult {
+    let scoped_path = match project_scope::resolve_scoped_path(&app, &file_path) {
+        Ok(path) => path,
+        Err(e) => {
+            return FileResult {
+                success: false,
+                data: None,
+                error: Some(e),
+            }
+        }
+    };
+
+    match fs::read_to_string(&scoped_path) {
         Ok(data) => FileResult {
             success: true,
             data: Some(data),
@@ -39,8 +57,19 @@ async fn read_file(file_path: String) -> FileResult {
 }
 
 #[tauri::command]
-async fn write_file(file_path: String, data: String) -> FileResult {
-    match fs::write(&file_path, data) {
+async fn write_file(app: tauri::AppHandle, file_path: String, data: String) -> FileResult {
+    let scoped_path = match project_scope::resolve_scoped_path(&app, &file_path) {
+        Ok(path) => path,
+        Err(e) => {
+            return FileResult {
+                success: false,
+                data: None,
+                error: Some(e),
+            }
+        }
+    };
+
+    match backup::atomic_write(&app, &scoped_path, data.as_bytes()) {
         Ok(_) => FileResult {
             success: true,
             data: None,
@@ -49,7 +78,7 @@ async fn write_file(file_path: String, data: String) -> FileResult {
         Err(e) => FileResult {
             success: false,
             data: None,
-            error: Some(e.to_string()),
+            error: Some(e),
         },
     }
 }
@@ -81,7 +110,7 @@ async fn save_settings(app: tauri::AppHandle, settings_json: String) -> FileResu
         let _ = fs::create_dir_all(parent);
     }
 
-    match fs::write(&settings_path, settings_json) {
+    match backup::atomic_write(&app, &settings_path, settings_json.as_bytes()) {
         Ok(_) => FileResult {
             success: true,
             data: None,
@@ -90,12 +119,12 @@ async fn save_settings(app: tauri::AppHandle, settings_json: String) -> FileResu
         Err(e) => FileResult {
             success: false,
             data: None,
-            error: Some(e.to_string()),
+            error: Some(e),
         },
     }
 }
 
-fn get_settings_path(app: &tauri::AppHandle) -> PathBuf {
+pub(crate) fn get_settings_path(app: &tauri::AppHandle) -> PathBuf {
     app.path()
         .app_data_dir()
         .expect("Failed to get app data dir")
@@ -239,14 +268,31 @@ async fn show_save_dialog(app: tauri::AppHandle, options: serde_json::Value) ->
 }
 
 #[tauri::command]
-async fn rebuild_menu() {
-    // Menu rebuild will be handled through Tauri's menu system
-    // This is a placeholder for compatibility
+async fn rebuild_menu(app: tauri::AppHandle) {
+    rebuild_menu_now(&app);
+}
+
+/// Regenerate the native menu, e.g. after the recent-projects list changes.
+pub(crate) fn rebuild_menu_now(app: &tauri::AppHandle) {
+    if let Err(e) = create_menu(app) {
+        eprintln!("Failed to rebuild menu: {}", e);
+    }
 }
 
 #[tauri::command]
-async fn create_dir(path: String) -> FileResult {
-    match fs::create_dir_all(&path) {
+async fn create_dir(app: tauri::AppHandle, path: String) -> FileResult {
+    let scoped_path = match project_scope::resolve_scoped_path(&app, &path) {
+        Ok(path) => path,
+        Err(e) => {
+            return FileResult {
+                success: false,
+                data: None,
+                error: Some(e),
+            }
+        }
+    };
+
+    match fs::create_dir_all(&scoped_path) {
         Ok(_) => FileResult {
             success: true,
             data: None,
@@ -263,6 +309,32 @@ async fn create_dir(path: String) -> FileResult {
 fn create_menu(app: &tauri::AppHandle) -> Result<(), Box<dyn std::error::Error>> {
     use tauri::menu::{MenuBuilder, MenuItemBuilder, SubmenuBuilder};
 
+    let recent_projects = recent_projects::load_recent_projects(app);
+    let open_recent_items: Vec<tauri::menu::MenuItem<tauri::Wry>> = if recent_projects.is_empty()
+    {
+        vec![MenuItemBuilder::with_id("open-recent:none", "No Recent Projects")
+            .enabled(false)
+            .build(app)?]
+    } else {
+        recent_projects
+            .iter()
+            .map(|path| {
+                let label = Path::new(path)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| path.clone());
+                MenuItemBuilder::with_id(format!("open-recent:{}", path), label).build(app)
+            })
+            .collect::<Result<_, _>>()?
+    };
+    let open_recent_refs: Vec<&dyn tauri::menu::IsMenuItem<tauri::Wry>> = open_recent_items
+        .iter()
+        .map(|item| item as &dyn tauri::menu::IsMenuItem<tauri::Wry>)
+        .collect();
+    let open_recent_menu = SubmenuBuilder::new(app, "Open Recent")
+        .items(&open_recent_refs)
+        .build()?;
+
     let menu = MenuBuilder::new(app)
         .items(&[
             &SubmenuBuilder::new(app, "Default").build()?,
@@ -275,6 +347,7 @@ fn create_menu(app: &tauri::AppHandle) -> Result<(), Box<dyn std::error::Error>>
                     &MenuItemBuilder::with_id("open-project", "Open Project")
                         .accelerator("CmdOrCtrl+O")
                         .build(app)?,
+                    &open_recent_menu,
                     &MenuItemBuilder::with_id("new-map", "New Map")
                         .accelerator("CmdOrCtrl+M")
                         .build(app)?,
@@ -321,6 +394,8 @@ fn create_menu(app: &tauri::AppHandle) -> Result<(), Box<dyn std::error::Error>>
                     &MenuItemBuilder::with_id("toggle-devtools", "Toggle DevTools")
                         .accelerator("F12")
                         .build(app)?,
+                    &MenuItemBuilder::with_id("check-for-updates", "Check for Updates…")
+                        .build(app)?,
                 ])
                 .build()?,
         ])
@@ -337,6 +412,12 @@ fn main() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_store::Builder::new().build())
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_updater::Builder::new().build())
+        .manage(project_scope::ProjectScopeState::new())
+        .manage(watcher::WatcherState::new())
+        .register_uri_scheme_protocol("lost-asset", |ctx, request| {
+            asset_protocol::handle_asset_request(ctx.app_handle(), &request)
+        })
         .setup(|app| {
             // Create menu
             if let Err(e) = create_menu(&app.handle()) {
@@ -378,7 +459,16 @@ fn main() {
                                 let _ = window.open_devtools();
                             }
                         }
-                        _ => {}
+                        "check-for-updates" => {
+                            let _ = window.emit("menu:check-for-updates", ());
+                        }
+                        id => {
+                            if let Some(path) = id.strip_prefix("open-recent:") {
+                                if path != "none" {
+                                    let _ = window.emit("menu:open-project", path);
+                                }
+                            }
+                        }
                     }
                 }
             });
@@ -419,7 +509,17 @@ fn main() {
             show_open_dialog,
             show_save_dialog,
             rebuild_menu,
-            create_dir
+            create_dir,
+            project_scope::set_project_root,
+            watcher::watch_project,
+            watcher::unwatch_project,
+            updater::check_for_update,
+            updater::download_and_install_update,
+            backup::list_backups,
+            backup::restore_backup,
+            recent_projects::add_recent_project,
+            recent_projects::get_recent_projects,
+            recent_projects::clear_recent_projects
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,102 @@
+// Project-scope subsystem: tracks the canonicalized root of the currently open
+// project and confines file commands to it (plus the app data dir for settings).
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use tauri::Manager;
+
+/// The canonicalized root of the currently open project, if one is open.
+pub struct ProjectScopeState(Mutex<Option<PathBuf>>);
+
+impl ProjectScopeState {
+    pub fn new() -> Self {
+        Self(Mutex::new(None))
+    }
+}
+
+/// Record the directory of the project the frontend just opened as the allowed
+/// scope for subsequent file commands.
+#[tauri::command]
+pub async fn set_project_root(
+    app: tauri::AppHandle,
+    project_path: String,
+) -> Result<(), String> {
+    let canonical = Path::new(&project_path)
+        .canonicalize()
+        .map_err(|e| e.to_string())?;
+
+    *app.state::<ProjectScopeState>().0.lock().unwrap() = Some(canonical);
+    Ok(())
+}
+
+/// The currently open project's canonicalized root, if any.
+pub fn current_root(app: &tauri::AppHandle) -> Option<PathBuf> {
+    app.state::<ProjectScopeState>().0.lock().unwrap().clone()
+}
+
+/// Canonicalize `path` and verify it resolves inside the currently open project
+/// root or the app data dir. Returns an error describing why the path was
+/// rejected rather than ever touching the disk for an out-of-scope path.
+pub fn resolve_scoped_path(app: &tauri::AppHandle, path: &str) -> Result<PathBuf, String> {
+    let canonical = canonicalize_for_scope_check(Path::new(path))?;
+
+    let project_root = current_root(app);
+    let app_data_dir = app.path().app_data_dir().ok();
+
+    let in_project = project_root
+        .as_ref()
+        .is_some_and(|root| canonical.starts_with(root));
+    let in_app_data = app_data_dir
+        .as_ref()
+        .is_some_and(|dir| canonical.starts_with(dir));
+
+    if in_project || in_app_data {
+        Ok(canonical)
+    } else {
+        Err("path outside project scope".to_string())
+    }
+}
+
+/// Canonicalize `requested` for the scope check above. If it doesn't exist yet
+/// (e.g. a file or a multi-level directory about to be created, as
+/// `create_dir` allows), walk up to the nearest existing ancestor, canonicalize
+/// that, and rejoin the missing trailing components — mirroring what
+/// `fs::create_dir_all` would create — rather than requiring the immediate
+/// parent to already exist.
+fn canonicalize_for_scope_check(requested: &Path) -> Result<PathBuf, String> {
+    if let Ok(canonical) = requested.canonicalize() {
+        return Ok(canonical);
+    }
+
+    let (existing_ancestor, mut missing) = nearest_existing_ancestor(requested)?;
+    missing.reverse();
+
+    let mut resolved = existing_ancestor
+        .canonicalize()
+        .map_err(|e| format!("failed to resolve path: {e}"))?;
+    resolved.extend(missing);
+
+    Ok(resolved)
+}
+
+/// Walk up `path`'s ancestors until one exists on disk, returning that ancestor
+/// along with the component names that were missing below it (nearest first).
+fn nearest_existing_ancestor(path: &Path) -> Result<(PathBuf, Vec<std::ffi::OsString>), String> {
+    let mut missing = Vec::new();
+    let mut current = path;
+
+    loop {
+        if current.exists() {
+            return Ok((current.to_path_buf(), missing));
+        }
+
+        match (current.file_name(), current.parent()) {
+            (Some(name), Some(parent)) => {
+                missing.push(name.to_os_string());
+                current = parent;
+            }
+            _ => return Err("failed to resolve path: no existing ancestor found".to_string()),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+// Backend-managed recent-projects list: dedupes, reorders, and prunes missing
+// entries so the "Open Recent" menu reflects what's actually still on disk.
+
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+use crate::{backup, get_settings_path};
+
+/// How many recent projects to keep in the list.
+const RECENT_PROJECTS_LIMIT: usize = 10;
+
+fn read_settings_value(app: &tauri::AppHandle) -> Value {
+    std::fs::read_to_string(get_settings_path(app))
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_else(|| json!({}))
+}
+
+fn write_settings_value(app: &tauri::AppHandle, settings: &Value) -> Result<(), String> {
+    let settings_path = get_settings_path(app);
+    if let Some(parent) = settings_path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let data = serde_json::to_string_pretty(settings).map_err(|e| e.to_string())?;
+    backup::atomic_write(app, &settings_path, data.as_bytes())
+}
+
+/// The current `recentFiles` list, deduplicated and pruned of paths that no
+/// longer exist on disk.
+fn recent_projects_from(settings: &Value) -> Vec<String> {
+    let existing: Vec<String> = settings["recentFiles"]
+        .as_array()
+        .map(|entries| entries.iter().filter_map(|v| v.as_str()).collect())
+        .unwrap_or_else(Vec::<&str>::new)
+        .into_iter()
+        .map(str::to_string)
+        .filter(|path| Path::new(path).exists())
+        .collect();
+    dedupe_keep_first(existing)
+}
+
+fn dedupe_keep_first(paths: Vec<String>) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    paths.into_iter().filter(|p| seen.insert(p.clone())).collect()
+}
+
+/// Load the recent-projects list directly from `settings.json`, pruning entries
+/// whose files no longer exist. Used synchronously when building the menu.
+pub fn load_recent_projects(app: &tauri::AppHandle) -> Vec<String> {
+    recent_projects_from(&read_settings_value(app))
+}
+
+/// Add `project_path` to the front of the recent-projects list, de-duplicating,
+/// dropping missing files, and capping the list at [`RECENT_PROJECTS_LIMIT`].
+#[tauri::command]
+pub async fn add_recent_project(
+    app: tauri::AppHandle,
+    project_path: String,
+) -> Result<Vec<String>, String> {
+    let mut settings = read_settings_value(&app);
+    let mut projects = recent_projects_from(&settings);
+
+    projects.retain(|p| p != &project_path);
+    projects.insert(0, project_path);
+    let mut projects = dedupe_keep_first(projects);
+    projects.truncate(RECENT_PROJECTS_LIMIT);
+
+    settings["recentFiles"] = json!(projects);
+    write_settings_value(&app, &settings)?;
+    crate::rebuild_menu_now(&app);
+
+    Ok(projects)
+}
+
+/// The current recent-projects list, pruned of files that no longer exist.
+#[tauri::command]
+pub async fn get_recent_projects(app: tauri::AppHandle) -> Vec<String> {
+    load_recent_projects(&app)
+}
+
+/// Clear the recent-projects list.
+#[tauri::command]
+pub async fn clear_recent_projects(app: tauri::AppHandle) -> Result<(), String> {
+    let mut settings = read_settings_value(&app);
+    settings["recentFiles"] = json!([]);
+    write_settings_value(&app, &settings)?;
+    crate::rebuild_menu_now(&app);
+    Ok(())
+}
@@ -0,0 +1,71 @@
+// Auto-update subsystem: checks for and installs new releases, streaming progress
+// back to the frontend instead of requiring a manual re-download.
+
+use serde::Serialize;
+use tauri::{Emitter, Manager};
+use tauri_plugin_updater::UpdaterExt;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AvailableUpdate {
+    version: String,
+    notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct UpdateProgressPayload {
+    downloaded: usize,
+    total: Option<u64>,
+}
+
+/// Check for an available update. Returns `None` if the app is already current.
+#[tauri::command]
+pub async fn check_for_update(app: tauri::AppHandle) -> Result<Option<AvailableUpdate>, String> {
+    let update = app.updater().map_err(|e| e.to_string())?.check().await;
+
+    match update.map_err(|e| e.to_string())? {
+        Some(update) => Ok(Some(AvailableUpdate {
+            version: update.version,
+            notes: update.body,
+        })),
+        None => Ok(None),
+    }
+}
+
+/// Download and install the available update, emitting `update-progress` events on
+/// the main window as bytes arrive and `update-ready` once the download has
+/// finished, just before the update is installed.
+#[tauri::command]
+pub async fn download_and_install_update(app: tauri::AppHandle) -> Result<(), String> {
+    let update = app
+        .updater()
+        .map_err(|e| e.to_string())?
+        .check()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "no update available".to_string())?;
+
+    let main_window = app
+        .get_webview_window("main")
+        .ok_or_else(|| "main window not found".to_string())?;
+    let progress_window = main_window.clone();
+    let mut downloaded = 0usize;
+
+    update
+        .download_and_install(
+            move |chunk_length, content_length| {
+                downloaded += chunk_length;
+                let _ = progress_window.emit(
+                    "update-progress",
+                    UpdateProgressPayload {
+                        downloaded,
+                        total: content_length,
+                    },
+                );
+            },
+            move || {
+                let _ = main_window.emit("update-ready", ());
+            },
+        )
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,175 @@
+// Filesystem watcher subsystem: live-reloads the frontend when project files are
+// modified externally (e.g. a tileset edited in another tool).
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use tauri::{Emitter, Manager};
+
+/// Bursts of events for the same path within this window are coalesced into a
+/// single emit, so one save doesn't fire the frontend repeatedly.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Active watchers, keyed by the project directory they were started for. Each
+/// entry also owns the background thread that flushes its debounced events.
+pub struct WatcherState(Mutex<HashMap<PathBuf, ProjectWatcher>>);
+
+impl WatcherState {
+    pub fn new() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+}
+
+struct ProjectWatcher {
+    _watcher: RecommendedWatcher,
+    stop: Arc<Mutex<bool>>,
+}
+
+impl Drop for ProjectWatcher {
+    fn drop(&mut self) {
+        *self.stop.lock().unwrap() = true;
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct FileChangedPayload {
+    path: String,
+    kind: &'static str,
+}
+
+fn change_kind(event_kind: &EventKind) -> Option<&'static str> {
+    match event_kind {
+        EventKind::Create(_) => Some("created"),
+        EventKind::Modify(_) => Some("modified"),
+        EventKind::Remove(_) => Some("removed"),
+        _ => None,
+    }
+}
+
+fn build_glob_set(globs: &[String]) -> Result<GlobSet, String> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in globs {
+        let glob = Glob::new(pattern).map_err(|e| e.to_string())?;
+        builder.add(glob);
+    }
+    builder.build().map_err(|e| e.to_string())
+}
+
+/// Spawn a watcher on `project_path`, forwarding debounced `file-changed` events for
+/// paths matching `globs` (e.g. `*.png`, `*.json`, `*.tileset`) to the frontend.
+#[tauri::command]
+pub async fn watch_project(
+    app: tauri::AppHandle,
+    project_path: String,
+    globs: Vec<String>,
+) -> Result<(), String> {
+    // Canonicalize so `strip_prefix` below lines up with the paths notify reports,
+    // which are canonical themselves (e.g. macOS resolves `/var` to `/private/var`).
+    let root = Path::new(&project_path)
+        .canonicalize()
+        .map_err(|e| e.to_string())?;
+    let glob_set = build_glob_set(&globs)?;
+
+    // Pending events are coalesced here, keyed by relative path, and flushed by the
+    // background thread below once `DEBOUNCE` has elapsed since the last update.
+    let pending: Arc<Mutex<HashMap<PathBuf, (&'static str, Instant)>>> =
+        Arc::new(Mutex::new(HashMap::new()));
+    let root_for_watcher = root.clone();
+    let pending_for_watcher = pending.clone();
+
+    let mut watcher = notify::recommended_watcher(move |result: notify::Result<Event>| {
+        let event = match result {
+            Ok(event) => event,
+            Err(_) => return,
+        };
+
+        let Some(kind) = change_kind(&event.kind) else {
+            return;
+        };
+
+        let mut pending = pending_for_watcher.lock().unwrap();
+        for path in &event.paths {
+            let Ok(relative) = path.strip_prefix(&root_for_watcher) else {
+                continue;
+            };
+            // Match on the file name rather than the full relative path, so a
+            // pattern like `*.png` applies in every directory of the project;
+            // directory parts of a pattern are not matched against anything.
+            let Some(file_name) = relative.file_name() else {
+                continue;
+            };
+            if !glob_set.is_match(file_name) {
+                continue;
+            }
+            pending.insert(relative.to_path_buf(), (kind, Instant::now()));
+        }
+    })
+    .map_err(|e| e.to_string())?;
+
+    watcher
+        .watch(&root, RecursiveMode::Recursive)
+        .map_err(|e| e.to_string())?;
+
+    let stop = Arc::new(Mutex::new(false));
+    spawn_flush_thread(app, pending, stop.clone());
+
+    app.state::<WatcherState>().0.lock().unwrap().insert(
+        root,
+        ProjectWatcher {
+            _watcher: watcher,
+            stop,
+        },
+    );
+
+    Ok(())
+}
+
+/// Poll `pending` for entries that have been quiet for `DEBOUNCE` and emit them,
+/// until `stop` is set (the watcher was torn down).
+fn spawn_flush_thread(
+    app: tauri::AppHandle,
+    pending: Arc<Mutex<HashMap<PathBuf, (&'static str, Instant)>>>,
+    stop: Arc<Mutex<bool>>,
+) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(Duration::from_millis(50));
+        if *stop.lock().unwrap() {
+            return;
+        }
+
+        let mut ready = Vec::new();
+        {
+            let mut pending = pending.lock().unwrap();
+            pending.retain(|path, (kind, seen_at)| {
+                if seen_at.elapsed() >= DEBOUNCE {
+                    ready.push((path.clone(), *kind));
+                    false
+                } else {
+                    true
+                }
+            });
+        }
+
+        for (path, kind) in ready {
+            let payload = FileChangedPayload {
+                path: path.to_string_lossy().into_owned(),
+                kind,
+            };
+            let _ = app.emit("file-changed", payload);
+        }
+    });
+}
+
+/// Tear down the watcher for `project_path`, if one is running.
+#[tauri::command]
+pub async fn unwatch_project(app: tauri::AppHandle, project_path: String) {
+    let root = Path::new(&project_path)
+        .canonicalize()
+        .unwrap_or_else(|_| PathBuf::from(&project_path));
+    app.state::<WatcherState>().0.lock().unwrap().remove(&root);
+}